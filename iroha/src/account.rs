@@ -0,0 +1,96 @@
+use crate::prelude::*;
+use std::collections::HashMap;
+
+/// An account: a named identity within a domain that holds assets and can be co-signed by
+/// more than one key.
+#[derive(Clone, Debug)]
+pub struct Account {
+    pub id: Id,
+    pub assets: HashMap<Id, Asset>,
+    pub signatories: Vec<PublicKey>,
+}
+
+impl Account {
+    pub fn new(id: Id) -> Self {
+        Account {
+            id,
+            assets: HashMap::new(),
+            signatories: Vec::new(),
+        }
+    }
+}
+
+pub mod isi {
+    use super::*;
+    use crate::isi::Contract;
+    use parity_scale_codec::{Decode, Encode};
+    use serde::{Deserialize, Serialize};
+
+    /// Adds a signatory's public key to an existing account, letting that key co-sign
+    /// transactions on the account's behalf. `Contract::invoke` does not execute this
+    /// instruction: it returns an error and leaves the account unchanged.
+    #[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
+    pub struct AddSignatory {
+        pub account_id: Id,
+        pub public_key: PublicKey,
+    }
+
+    impl std::convert::From<AddSignatory> for Contract {
+        fn from(command_payload: AddSignatory) -> Self {
+            Contract::AddSignatory(command_payload)
+        }
+    }
+
+    /// Appends a role name to an account. `Contract::invoke` does not execute this
+    /// instruction: it returns an error and leaves the account unchanged.
+    #[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
+    pub struct AppendRole {
+        pub account_id: Id,
+        pub role_name: String,
+    }
+
+    impl std::convert::From<AppendRole> for Contract {
+        fn from(command_payload: AppendRole) -> Self {
+            Contract::AppendRole(command_payload)
+        }
+    }
+
+    /// Creates a new account, registering `public_key` as its first signatory.
+    #[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
+    pub struct CreateAccount {
+        pub account_name: String,
+        pub domain_id: String,
+        pub public_key: PublicKey,
+    }
+
+    impl Instruction for CreateAccount {
+        fn execute(&self, world_state_view: &mut WorldStateView) -> Result<(), String> {
+            let id = Id::new(&self.account_name, &self.domain_id);
+            let mut account = Account::new(id.clone());
+            account.signatories.push(self.public_key.clone());
+            world_state_view.world.accounts.insert(id, account);
+            Ok(())
+        }
+    }
+
+    impl std::convert::From<CreateAccount> for Contract {
+        fn from(command_payload: CreateAccount) -> Self {
+            Contract::CreateAccount(command_payload)
+        }
+    }
+
+    /// Registers a new role name with a fixed set of permission strings, to later be appended
+    /// to accounts via `AppendRole`. `Contract::invoke` does not execute this instruction: it
+    /// returns an error and records no role.
+    #[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
+    pub struct CreateRole {
+        pub role_name: String,
+        pub permissions: Vec<String>,
+    }
+
+    impl std::convert::From<CreateRole> for Contract {
+        fn from(command_payload: CreateRole) -> Self {
+            Contract::CreateRole(command_payload)
+        }
+    }
+}
@@ -1,14 +1,17 @@
 use crate::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Asset {
     /// identifier of asset, formatted as asset_name#domain_id
     pub id: Id,
+    /// quantity of this asset held on the owning account, starts at zero until credited
+    pub quantity: u128,
 }
 
 impl Asset {
     pub fn new(id: Id) -> Self {
-        Asset { id }
+        Asset { id, quantity: 0 }
     }
 }
 
@@ -16,11 +19,12 @@ pub mod isi {
     use super::*;
     use crate::isi::Contract;
     use parity_scale_codec::{Decode, Encode};
+    use serde::{Deserialize, Serialize};
 
     /// The purpose of add asset quantity command is to increase the quantity of an asset on account of
     /// transaction creator. Use case scenario is to increase the number of a mutable asset in the
     /// system, which can act as a claim on a commodity (e.g. money, gold, etc.).
-    #[derive(Clone, Debug, PartialEq, Encode, Decode)]
+    #[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
     pub struct AddAssetQuantity {
         pub asset_id: Id,
         pub account_id: Id,
@@ -29,12 +33,18 @@ pub mod isi {
 
     impl Instruction for AddAssetQuantity {
         fn execute(&self, world_state_view: &mut WorldStateView) -> Result<(), String> {
-            world_state_view
+            let account = world_state_view
                 .world
                 .account(&self.account_id)
-                .unwrap()
+                .ok_or_else(|| format!("Account not found: {:?}", self.account_id))?;
+            let asset = account
                 .assets
-                .insert(self.asset_id.clone(), Asset::new(self.asset_id.clone()));
+                .entry(self.asset_id.clone())
+                .or_insert_with(|| Asset::new(self.asset_id.clone()));
+            asset.quantity = asset
+                .quantity
+                .checked_add(self.amount)
+                .ok_or_else(|| "Asset quantity would overflow.".to_string())?;
             Ok(())
         }
     }
@@ -93,7 +103,7 @@ pub mod isi {
 
     /// The purpose of сreate asset command is to create a new type of asset, unique in a domain.
     /// An asset is a countable representation of a commodity.
-    #[derive(Clone, Debug, PartialEq, Encode, Decode)]
+    #[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
     pub struct CreateAsset {
         pub asset_name: String,
         pub domain_id: String,
@@ -155,7 +165,7 @@ pub mod isi {
 
     /// The purpose of transfer asset command is to share assets within the account in peer
     /// network: in the way that source account transfers assets to the target account.
-    #[derive(Clone, Debug, PartialEq, Encode, Decode)]
+    #[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
     pub struct TransferAsset {
         pub source_account_id: Id,
         pub destination_account_id: Id,
@@ -166,19 +176,39 @@ pub mod isi {
 
     impl Instruction for TransferAsset {
         fn execute(&self, world_state_view: &mut WorldStateView) -> Result<(), String> {
-            let asset = world_state_view
+            if world_state_view
                 .world
-                .account(&self.source_account_id)
-                .unwrap()
-                .assets
-                .remove(&self.asset_id)
-                .unwrap();
-            world_state_view
+                .account(&self.destination_account_id)
+                .is_none()
+            {
+                return Err(format!("Account not found: {:?}", self.destination_account_id));
+            }
+            {
+                let source_account = world_state_view
+                    .world
+                    .account(&self.source_account_id)
+                    .ok_or_else(|| format!("Account not found: {:?}", self.source_account_id))?;
+                let asset = source_account
+                    .assets
+                    .get_mut(&self.asset_id)
+                    .ok_or_else(|| format!("Asset not found: {:?}", self.asset_id))?;
+                asset.quantity = asset
+                    .quantity
+                    .checked_sub(self.amount)
+                    .ok_or_else(|| "Not enough asset quantity to transfer.".to_string())?;
+            }
+            let destination_account = world_state_view
                 .world
                 .account(&self.destination_account_id)
-                .unwrap()
+                .ok_or_else(|| format!("Account not found: {:?}", self.destination_account_id))?;
+            let asset = destination_account
                 .assets
-                .insert(self.asset_id.clone(), asset);
+                .entry(self.asset_id.clone())
+                .or_insert_with(|| Asset::new(self.asset_id.clone()));
+            asset.quantity = asset
+                .quantity
+                .checked_add(self.amount)
+                .ok_or_else(|| "Asset quantity would overflow.".to_string())?;
             Ok(())
         }
     }
@@ -0,0 +1,142 @@
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+/// A public key belonging to one of the network's guardians, i.e. peers empowered to attest
+/// to cross-peer messages such as bridge transfers.
+pub type PublicKey = Vec<u8>;
+
+/// A versioned set of guardian public keys. Attestations are verified against a specific
+/// `GuardianSet`, so rotating guardians only requires publishing a new version with a higher
+/// `activation_height` — attestations sequenced below that height keep verifying against
+/// whichever set was active for them, instead of being invalidated by the rotation.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct GuardianSet {
+    pub version: u32,
+    pub guardians: Vec<PublicKey>,
+    pub activation_height: u64,
+}
+
+impl GuardianSet {
+    pub fn new(version: u32, guardians: Vec<PublicKey>, activation_height: u64) -> Self {
+        GuardianSet {
+            version,
+            guardians,
+            activation_height,
+        }
+    }
+
+    /// Minimum number of distinct, valid signatures an attestation needs to be accepted:
+    /// `floor(2/3 * N) + 1`.
+    pub fn quorum(&self) -> usize {
+        2 * self.guardians.len() / 3 + 1
+    }
+}
+
+/// Picks the guardian set that was active at `height`, i.e. the highest-versioned set whose
+/// `activation_height` does not exceed it. Used to verify an `Attestation` against the set
+/// its signatures were actually collected under, even after later rotations.
+pub fn active_guardian_set(sets: &[GuardianSet], height: u64) -> Option<&GuardianSet> {
+    sets.iter()
+        .filter(|set| set.activation_height <= height)
+        .max_by_key(|set| set.version)
+}
+
+/// A verifiable attestation, in the style of a Wormhole VAA, over an arbitrary SCALE-encoded
+/// payload. `sequence` increases monotonically so a payload can't be replayed, and
+/// `signatures` carries one `(peer_index, signature)` pair per attesting guardian, indexed
+/// into the `GuardianSet` it was collected under.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct Attestation {
+    pub payload: Vec<u8>,
+    pub sequence: u64,
+    pub signatures: Vec<(u32, Vec<u8>)>,
+}
+
+impl Attestation {
+    pub fn new(payload: Vec<u8>, sequence: u64) -> Self {
+        Attestation {
+            payload,
+            sequence,
+            signatures: Vec::new(),
+        }
+    }
+
+    /// Verifies this attestation against `set`: every `(peer_index, signature)` pair must
+    /// name a distinct, in-range guardian, and at least `set.quorum()` of the pairs must carry
+    /// a valid signature over the hash of `payload`; a pair whose signature does not verify is
+    /// not counted towards the quorum but does not by itself reject the attestation. Returns the attested
+    /// payload bytes on success so the caller can decode and act on it.
+    pub fn verify(&self, set: &GuardianSet) -> Result<Vec<u8>, String> {
+        let hash = crate::crypto::hash(&self.payload);
+        let mut seen = std::collections::HashSet::new();
+        let mut valid = 0;
+        for (peer_index, signature) in &self.signatures {
+            let index = *peer_index as usize;
+            let guardian = set
+                .guardians
+                .get(index)
+                .ok_or_else(|| format!("Guardian index out of range: {}", index))?;
+            if !seen.insert(index) {
+                return Err(format!("Duplicate signature from guardian {}", index));
+            }
+            if crate::crypto::verify(guardian, &hash, signature) {
+                valid += 1;
+            }
+        }
+        let required = set.quorum();
+        if valid < required {
+            return Err(format!(
+                "Attestation carries {} valid signatures, quorum of {} required.",
+                valid, required
+            ));
+        }
+        Ok(self.payload.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guardian_set_quorum_is_two_thirds_plus_one() {
+        let set = GuardianSet::new(0, vec![vec![0]; 4], 0);
+        assert_eq!(set.quorum(), 3);
+    }
+
+    #[test]
+    fn active_guardian_set_picks_latest_activated_version() {
+        let genesis = GuardianSet::new(0, vec![vec![0]], 0);
+        let rotated = GuardianSet::new(1, vec![vec![0], vec![1]], 100);
+        let sets = vec![genesis.clone(), rotated.clone()];
+        assert_eq!(active_guardian_set(&sets, 50), Some(&genesis));
+        assert_eq!(active_guardian_set(&sets, 150), Some(&rotated));
+    }
+
+    #[test]
+    fn attestation_serialization_and_deserialization() {
+        let expected = Attestation {
+            payload: vec![1, 2, 3],
+            sequence: 7,
+            signatures: vec![(0, vec![9, 9]), (1, vec![8, 8])],
+        };
+        let actual = Attestation::decode(&mut expected.encode().as_slice()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn attestation_verifies_against_the_guardian_set_stored_on_world_state_view() {
+        let key = vec![1, 2, 3];
+        let mut world_state_view = crate::wsv::WorldStateView::new();
+        world_state_view
+            .guardian_sets
+            .push(GuardianSet::new(0, vec![key.clone()], 0));
+        let payload = vec![9, 9, 9];
+        let signature = crate::crypto::sign(&key, &crate::crypto::hash(&payload));
+        let mut attestation = Attestation::new(payload.clone(), 0);
+        attestation.signatures.push((0, signature));
+        let guardian_set =
+            active_guardian_set(&world_state_view.guardian_sets, attestation.sequence).unwrap();
+        assert_eq!(attestation.verify(guardian_set).unwrap(), payload);
+    }
+}
@@ -0,0 +1,305 @@
+use crate::prelude::*;
+
+/// Decides whether `instruction`, submitted by `origin`, may run at all, before any weight or
+/// fee accounting happens. `WorldStateView` keeps an ordered stack of barriers; an instruction
+/// is allowed through as soon as one of them returns `Ok`, and refused if every barrier in the
+/// stack returns `Err`. Modeled on the barrier stage of cross-consensus message execution.
+pub trait Barrier {
+    fn should_execute(&self, origin: &Id, instruction: &Contract) -> Result<(), String>;
+}
+
+/// Lets instructions through for free, but only for origins on a fixed allow-list of trusted
+/// peers.
+pub struct AllowUnpaidExecutionFrom(pub std::collections::HashSet<Id>);
+
+impl Barrier for AllowUnpaidExecutionFrom {
+    fn should_execute(&self, origin: &Id, _instruction: &Contract) -> Result<(), String> {
+        if self.0.contains(origin) {
+            Ok(())
+        } else {
+            Err(format!("{:?} is not allowed unpaid execution.", origin))
+        }
+    }
+}
+
+/// Lets an allow-listed origin through on the condition that it pays weight-proportional fees;
+/// the fee itself is deducted later, by `charge`, once the batch's total weight is known. This
+/// barrier only checks that `origin` is one that is allowed to pay.
+pub struct AllowPaidExecutionFrom(pub std::collections::HashSet<Id>);
+
+impl Barrier for AllowPaidExecutionFrom {
+    fn should_execute(&self, origin: &Id, _instruction: &Contract) -> Result<(), String> {
+        if self.0.contains(origin) {
+            Ok(())
+        } else {
+            Err(format!("{:?} is not allowed paid execution.", origin))
+        }
+    }
+}
+
+/// Execution weight, in the same abstract unit `WeightBounds` assigns to every `Contract`
+/// variant and meters batches against.
+pub type Weight = u64;
+
+/// A fee asset and the amount of it charged per unit of weight consumed.
+pub struct Fee {
+    pub asset_id: Id,
+    pub per_weight: u128,
+}
+
+/// Assigns a static `Weight` to each `Contract` variant and enforces a per-block limit on the
+/// total weight a batch of instructions may consume. `WorldStateView` owns the active
+/// `WeightBounds` alongside its barrier stack.
+#[derive(Clone)]
+pub struct WeightBounds {
+    pub max_weight_per_block: Weight,
+}
+
+impl WeightBounds {
+    pub fn weight_of(&self, instruction: &Contract) -> Weight {
+        use Contract::*;
+        match instruction {
+            AddAssetQuantity(_) => 1,
+            TransferAsset(_) => 2,
+            CreateAsset(_) | CreateAccount(_) | CreateDomain(_) => 3,
+            AddPeer(_) => 2,
+            LockAsset(_) => 3,
+            MintWrappedAsset(_) => 5,
+            RegisterBridgeContract(_) => 2,
+            _ => 1,
+        }
+    }
+
+    /// Sums the weight of every instruction in the batch, rejecting it outright if the total
+    /// exceeds `max_weight_per_block`.
+    pub fn check_batch(&self, instructions: &[Contract]) -> Result<Weight, String> {
+        let total: Weight = instructions.iter().map(|instruction| self.weight_of(instruction)).sum();
+        if total > self.max_weight_per_block {
+            Err(format!(
+                "Batch weight {} exceeds the per-block limit of {}.",
+                total, self.max_weight_per_block
+            ))
+        } else {
+            Ok(total)
+        }
+    }
+}
+
+/// Deducts `weight * fee.per_weight` of `fee.asset_id` from `origin`'s balance, reusing the same
+/// overflow-checked accounting as `asset::isi::AddAssetQuantity`/`TransferAsset`.
+fn charge(
+    origin: &Id,
+    weight: Weight,
+    fee: &Fee,
+    world_state_view: &mut WorldStateView,
+) -> Result<(), String> {
+    let amount = fee
+        .per_weight
+        .checked_mul(weight as u128)
+        .ok_or_else(|| "Execution fee would overflow.".to_string())?;
+    let account = world_state_view
+        .world
+        .account(origin)
+        .ok_or_else(|| format!("Account not found: {:?}", origin))?;
+    let asset = account
+        .assets
+        .get_mut(&fee.asset_id)
+        .ok_or_else(|| format!("Fee asset not found: {:?}", fee.asset_id))?;
+    asset.quantity = asset
+        .quantity
+        .checked_sub(amount)
+        .ok_or_else(|| "Insufficient balance to pay the execution fee.".to_string())?;
+    Ok(())
+}
+
+/// Refunds `unused_weight * fee.per_weight` of `fee.asset_id` back to `origin` once the actual
+/// weight consumed by a batch turns out to be less than what was charged up front.
+fn refund(
+    origin: &Id,
+    unused_weight: Weight,
+    fee: &Fee,
+    world_state_view: &mut WorldStateView,
+) -> Result<(), String> {
+    let amount = fee
+        .per_weight
+        .checked_mul(unused_weight as u128)
+        .ok_or_else(|| "Execution fee refund would overflow.".to_string())?;
+    let account = world_state_view
+        .world
+        .account(origin)
+        .ok_or_else(|| format!("Account not found: {:?}", origin))?;
+    let asset = account
+        .assets
+        .entry(fee.asset_id.clone())
+        .or_insert_with(|| Asset::new(fee.asset_id.clone()));
+    asset.quantity = asset
+        .quantity
+        .checked_add(amount)
+        .ok_or_else(|| "Execution fee refund would overflow balance.".to_string())?;
+    Ok(())
+}
+
+/// Replaces the bare `Contract::invoke` dispatch with the full barrier/weight pipeline: every
+/// instruction must be let through by at least one of `world_state_view`'s active barriers, the
+/// batch's total weight must fit `world_state_view`'s `WeightBounds`, and, when `fee` is given,
+/// that weight is charged up front and any unused portion refunded once execution completes.
+///
+/// Charging, execution and refunding all happen against a scratch copy of `world_state_view`,
+/// the same way `Transaction::execute` isolates a batch from the real state — so an instruction
+/// failing partway through the batch leaves `world_state_view` untouched instead of having
+/// charged `origin` for weight that was never actually consumed.
+pub fn invoke_with_barriers(
+    world_state_view: &mut WorldStateView,
+    origin: &Id,
+    instructions: &[Contract],
+    fee: Option<&Fee>,
+) -> Result<(), String> {
+    for instruction in instructions {
+        let allowed = world_state_view
+            .barriers
+            .iter()
+            .any(|barrier| barrier.should_execute(origin, instruction).is_ok());
+        if !allowed {
+            return Err(format!(
+                "No barrier allows {:?} to execute this instruction.",
+                origin
+            ));
+        }
+    }
+    let total_weight = world_state_view.weight_bounds.check_batch(instructions)?;
+    let mut scratch = world_state_view.clone();
+    if let Some(fee) = fee {
+        charge(origin, total_weight, fee, &mut scratch)?;
+    }
+    let mut used_weight = 0;
+    for instruction in instructions {
+        instruction.invoke(&mut scratch)?;
+        used_weight += scratch.weight_bounds.weight_of(instruction);
+    }
+    if let Some(fee) = fee {
+        let unused = total_weight.saturating_sub(used_weight);
+        if unused > 0 {
+            refund(origin, unused, fee, &mut scratch)?;
+        }
+    }
+    *world_state_view = scratch;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allow_unpaid_execution_from_rejects_unlisted_origins() {
+        let trusted = Id::new("trusted_peer", "domain");
+        let untrusted = Id::new("untrusted_peer", "domain");
+        let barrier = AllowUnpaidExecutionFrom(std::iter::once(trusted.clone()).collect());
+        let instruction = Contract::AddAssetQuantity(asset::isi::AddAssetQuantity {
+            asset_id: Id::new("xor", "domain"),
+            account_id: trusted.clone(),
+            amount: 1,
+        });
+        assert!(barrier.should_execute(&trusted, &instruction).is_ok());
+        assert!(barrier.should_execute(&untrusted, &instruction).is_err());
+    }
+
+    #[test]
+    fn invoke_with_barriers_charges_the_fee_against_the_origins_balance() {
+        let origin = Id::new("account", "domain");
+        let fee_asset_id = Id::new("xor", "domain");
+        let mut world_state_view = WorldStateView::new();
+        let mut account = Account::new(origin.clone());
+        account
+            .assets
+            .insert(fee_asset_id.clone(), Asset { id: fee_asset_id.clone(), quantity: 100 });
+        world_state_view.world.accounts.insert(origin.clone(), account);
+        world_state_view.barriers.push(std::sync::Arc::new(
+            AllowPaidExecutionFrom(std::iter::once(origin.clone()).collect()),
+        ));
+        world_state_view.weight_bounds = WeightBounds {
+            max_weight_per_block: 10,
+        };
+        let fee = Fee {
+            asset_id: fee_asset_id.clone(),
+            per_weight: 3,
+        };
+        let instruction = Contract::AddAssetQuantity(asset::isi::AddAssetQuantity {
+            asset_id: Id::new("knc", "domain"),
+            account_id: origin.clone(),
+            amount: 1,
+        });
+        // AddAssetQuantity weighs 1, so the whole batch's weight is used: charge deducts exactly
+        // `1 * per_weight` up front and there is nothing left over to refund.
+        invoke_with_barriers(
+            &mut world_state_view,
+            &origin,
+            std::slice::from_ref(&instruction),
+            Some(&fee),
+        )
+        .unwrap();
+        let account = world_state_view.world.account(&origin).unwrap();
+        assert_eq!(account.assets.get(&fee_asset_id).unwrap().quantity, 97);
+        assert_eq!(account.assets.get(&Id::new("knc", "domain")).unwrap().quantity, 1);
+    }
+
+    #[test]
+    fn invoke_with_barriers_leaves_world_state_view_untouched_on_a_mid_batch_failure() {
+        let origin = Id::new("account", "domain");
+        let fee_asset_id = Id::new("xor", "domain");
+        let mut world_state_view = WorldStateView::new();
+        let mut account = Account::new(origin.clone());
+        account
+            .assets
+            .insert(fee_asset_id.clone(), Asset { id: fee_asset_id.clone(), quantity: 100 });
+        world_state_view.world.accounts.insert(origin.clone(), account);
+        world_state_view.barriers.push(std::sync::Arc::new(
+            AllowPaidExecutionFrom(std::iter::once(origin.clone()).collect()),
+        ));
+        world_state_view.weight_bounds = WeightBounds {
+            max_weight_per_block: 10,
+        };
+        let fee = Fee {
+            asset_id: fee_asset_id.clone(),
+            per_weight: 3,
+        };
+        let succeeds = Contract::AddAssetQuantity(asset::isi::AddAssetQuantity {
+            asset_id: Id::new("knc", "domain"),
+            account_id: origin.clone(),
+            amount: 1,
+        });
+        let fails = Contract::TransferAsset(asset::isi::TransferAsset {
+            source_account_id: origin.clone(),
+            destination_account_id: Id::new("other_account", "domain"),
+            asset_id: Id::new("knc", "domain"),
+            description: "description".to_string(),
+            amount: 1,
+        });
+        let instructions = vec![succeeds, fails];
+        assert!(invoke_with_barriers(&mut world_state_view, &origin, &instructions, Some(&fee))
+            .is_err());
+        let account = world_state_view.world.account(&origin).unwrap();
+        assert_eq!(account.assets.get(&fee_asset_id).unwrap().quantity, 100);
+        assert!(!account.assets.contains_key(&Id::new("knc", "domain")));
+    }
+
+    #[test]
+    fn weight_bounds_rejects_batches_over_the_block_limit() {
+        let weight_bounds = WeightBounds {
+            max_weight_per_block: 3,
+        };
+        let transfer = Contract::TransferAsset(asset::isi::TransferAsset {
+            source_account_id: Id::new("source", "domain"),
+            destination_account_id: Id::new("destination", "domain"),
+            asset_id: Id::new("xor", "domain"),
+            description: "description".to_string(),
+            amount: 1,
+        });
+        assert!(weight_bounds
+            .check_batch(std::slice::from_ref(&transfer))
+            .is_ok());
+        assert!(weight_bounds
+            .check_batch(&[transfer.clone(), transfer])
+            .is_err());
+    }
+}
@@ -0,0 +1,402 @@
+use crate::prelude::*;
+
+/// Maps an asset native to its origin domain to the wrapped `Asset` minted for it in a
+/// remote domain. Kept on `WorldStateView` so a `MintWrappedAsset` can look up (or register)
+/// the wrapped counterpart of a locked asset, and so wrapped supply can always be traced
+/// back to the quantity actually escrowed on the origin side.
+pub type WrappedAssetRegistry = std::collections::HashMap<Id, Id>;
+
+/// Trusted remote bridge addresses, keyed by the domain that trusts them. Only a transfer
+/// payload originating from one of these addresses should ever be allowed to mint a wrapped
+/// asset into the domain.
+pub type BridgeContracts = std::collections::HashMap<String, Vec<String>>;
+
+pub mod isi {
+    use super::*;
+    use crate::attestation::{active_guardian_set, Attestation};
+    use crate::isi::Contract;
+    use parity_scale_codec::{Decode, Encode};
+    use serde::{Deserialize, Serialize};
+
+    /// The purpose of lock asset command is to escrow a quantity of an asset native to this
+    /// domain into the domain's bridge account, so that it can be represented as a wrapped
+    /// asset on `target_domain`. This mirrors the lock-and-mint pattern used by token bridges:
+    /// wrapped supply can never exceed what is actually escrowed here.
+    #[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
+    pub struct LockAsset {
+        pub asset_id: Id,
+        pub account_id: Id,
+        pub amount: u128,
+        pub target_domain: String,
+        /// The bridge address on this domain originating the transfer, so `target_domain` can
+        /// verify it against its own `bridge_contracts` before minting.
+        pub origin_bridge_address: String,
+    }
+
+    impl LockAsset {
+        /// Identifier of the account each domain escrows locked assets into.
+        pub fn escrow_account_id(&self) -> Id {
+            Id::new("bridge_escrow", &self.asset_id.1)
+        }
+
+        /// SCALE-encoded payload describing the locked transfer, to be carried across the
+        /// bridge and attested to by a quorum of peers before `MintWrappedAsset` is run.
+        pub fn transfer_payload(&self) -> Vec<u8> {
+            self.encode()
+        }
+    }
+
+    impl Instruction for LockAsset {
+        fn execute(&self, world_state_view: &mut WorldStateView) -> Result<(), String> {
+            if world_state_view
+                .world
+                .account(&self.escrow_account_id())
+                .is_none()
+            {
+                return Err("Bridge escrow account not found.".to_string());
+            }
+            let source_account = world_state_view
+                .world
+                .account(&self.account_id)
+                .ok_or_else(|| format!("Account not found: {:?}", self.account_id))?;
+            let asset = source_account
+                .assets
+                .get_mut(&self.asset_id)
+                .ok_or_else(|| format!("Asset not found: {:?}", self.asset_id))?;
+            asset.quantity = asset
+                .quantity
+                .checked_sub(self.amount)
+                .ok_or_else(|| "Not enough asset quantity to lock.".to_string())?;
+            let escrow_account = world_state_view
+                .world
+                .account(&self.escrow_account_id())
+                .ok_or_else(|| "Bridge escrow account not found.".to_string())?;
+            let escrowed = escrow_account
+                .assets
+                .entry(self.asset_id.clone())
+                .or_insert_with(|| Asset::new(self.asset_id.clone()));
+            escrowed.quantity = escrowed
+                .quantity
+                .checked_add(self.amount)
+                .ok_or_else(|| "Escrow quantity would overflow.".to_string())?;
+            Ok(())
+        }
+    }
+
+    impl std::convert::From<&LockAsset> for Vec<u8> {
+        fn from(command_payload: &LockAsset) -> Self {
+            command_payload.encode()
+        }
+    }
+
+    impl std::convert::From<LockAsset> for Contract {
+        fn from(command_payload: LockAsset) -> Self {
+            Contract::LockAsset(command_payload)
+        }
+    }
+
+    impl std::convert::From<Vec<u8>> for LockAsset {
+        fn from(command_payload: Vec<u8>) -> Self {
+            LockAsset::decode(&mut command_payload.as_slice())
+                .expect("Failed to deserialize payload.")
+        }
+    }
+
+    /// The purpose of mint wrapped asset command is to create, or credit, a wrapped
+    /// representation of a `LockAsset` transfer in the target domain. `attestation` must
+    /// wrap the originating `LockAsset::transfer_payload` and verify against the guardian
+    /// set active at its sequence, carrying signatures from at least a quorum of guardians,
+    /// so that minted supply can never outrun locked supply.
+    #[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
+    pub struct MintWrappedAsset {
+        pub wrapped_asset_id: Id,
+        pub account_id: Id,
+        pub attestation: Attestation,
+    }
+
+    impl Instruction for MintWrappedAsset {
+        fn execute(&self, world_state_view: &mut WorldStateView) -> Result<(), String> {
+            let guardian_set =
+                active_guardian_set(&world_state_view.guardian_sets, self.attestation.sequence)
+                    .ok_or_else(|| "No guardian set active for this attestation.".to_string())?;
+            let payload = self.attestation.verify(guardian_set)?;
+            let lock = LockAsset::decode(&mut payload.as_slice())
+                .map_err(|_| "Attested payload is not a LockAsset transfer.".to_string())?;
+            let target_domain = &self.wrapped_asset_id.1;
+            if lock.target_domain != *target_domain {
+                return Err(format!(
+                    "Locked transfer targets domain {:?}, not {:?}.",
+                    lock.target_domain, target_domain
+                ));
+            }
+            let trusted = world_state_view
+                .bridge_contracts
+                .get(target_domain)
+                .map(|addresses| addresses.contains(&lock.origin_bridge_address))
+                .unwrap_or(false);
+            if !trusted {
+                return Err(format!(
+                    "{:?} is not a bridge contract trusted by {:?}.",
+                    lock.origin_bridge_address, target_domain
+                ));
+            }
+            match world_state_view.wrapped_assets.get(&lock.asset_id) {
+                Some(registered) if *registered != self.wrapped_asset_id => {
+                    return Err("Origin asset is already wrapped as a different asset.".to_string())
+                }
+                _ => {}
+            }
+            let credited = {
+                let account = world_state_view
+                    .world
+                    .account(&self.account_id)
+                    .ok_or_else(|| format!("Account not found: {:?}", self.account_id))?;
+                let balance = account
+                    .assets
+                    .get(&self.wrapped_asset_id)
+                    .map(|asset| asset.quantity)
+                    .unwrap_or(0);
+                balance
+                    .checked_add(lock.amount)
+                    .ok_or_else(|| "Wrapped asset quantity would overflow.".to_string())?
+            };
+            // Only mark the sequence minted once every check above, including the balance
+            // update itself, is known to succeed: inserting any earlier would let a transient
+            // failure (bad signature, wrong domain, untrusted bridge, missing account, overflow)
+            // permanently burn a valid attestation, never crediting the locked funds but
+            // blocking every future retry with "has already been minted".
+            if !world_state_view
+                .processed_attestations
+                .insert(self.attestation.sequence)
+            {
+                return Err(format!(
+                    "Attestation sequence {} has already been minted.",
+                    self.attestation.sequence
+                ));
+            }
+            world_state_view
+                .wrapped_assets
+                .insert(lock.asset_id.clone(), self.wrapped_asset_id.clone());
+            world_state_view
+                .world
+                .account(&self.account_id)
+                .expect("already looked up above")
+                .assets
+                .entry(self.wrapped_asset_id.clone())
+                .or_insert_with(|| Asset::new(self.wrapped_asset_id.clone()))
+                .quantity = credited;
+            Ok(())
+        }
+    }
+
+    impl std::convert::From<&MintWrappedAsset> for Vec<u8> {
+        fn from(command_payload: &MintWrappedAsset) -> Self {
+            command_payload.encode()
+        }
+    }
+
+    impl std::convert::From<MintWrappedAsset> for Contract {
+        fn from(command_payload: MintWrappedAsset) -> Self {
+            Contract::MintWrappedAsset(command_payload)
+        }
+    }
+
+    impl std::convert::From<Vec<u8>> for MintWrappedAsset {
+        fn from(command_payload: Vec<u8>) -> Self {
+            MintWrappedAsset::decode(&mut command_payload.as_slice())
+                .expect("Failed to deserialize payload.")
+        }
+    }
+
+    /// The purpose of register bridge contract command is to record a remote bridge address
+    /// that a domain trusts to originate lock-and-mint transfers. `MintWrappedAsset` should
+    /// only ever be attested for transfers coming from a registered address.
+    #[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
+    pub struct RegisterBridgeContract {
+        pub domain_id: String,
+        pub remote_bridge_address: String,
+    }
+
+    impl Instruction for RegisterBridgeContract {
+        fn execute(&self, world_state_view: &mut WorldStateView) -> Result<(), String> {
+            world_state_view
+                .bridge_contracts
+                .entry(self.domain_id.clone())
+                .or_default()
+                .push(self.remote_bridge_address.clone());
+            Ok(())
+        }
+    }
+
+    impl std::convert::From<&RegisterBridgeContract> for Vec<u8> {
+        fn from(command_payload: &RegisterBridgeContract) -> Self {
+            command_payload.encode()
+        }
+    }
+
+    impl std::convert::From<RegisterBridgeContract> for Contract {
+        fn from(command_payload: RegisterBridgeContract) -> Self {
+            Contract::RegisterBridgeContract(command_payload)
+        }
+    }
+
+    impl std::convert::From<Vec<u8>> for RegisterBridgeContract {
+        fn from(command_payload: Vec<u8>) -> Self {
+            RegisterBridgeContract::decode(&mut command_payload.as_slice())
+                .expect("Failed to deserialize payload.")
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn lock_asset_command_serialization_and_deserialization() {
+            let expected = LockAsset {
+                asset_id: Id::new("xor", "domain"),
+                account_id: Id::new("account", "domain"),
+                amount: 2002,
+                target_domain: "other_domain".to_string(),
+                origin_bridge_address: "bridge_address".to_string(),
+            };
+            let actual = LockAsset::decode(&mut expected.encode().as_slice()).unwrap();
+            assert_eq!(expected, actual);
+        }
+
+        #[test]
+        fn mint_wrapped_asset_command_serialization_and_deserialization() {
+            let lock = LockAsset {
+                asset_id: Id::new("xor", "domain"),
+                account_id: Id::new("account", "domain"),
+                amount: 2002,
+                target_domain: "other_domain".to_string(),
+                origin_bridge_address: "bridge_address".to_string(),
+            };
+            let expected = MintWrappedAsset {
+                wrapped_asset_id: Id::new("xor.e", "other_domain"),
+                account_id: Id::new("account", "other_domain"),
+                attestation: Attestation::new(lock.transfer_payload(), 0),
+            };
+            let actual = MintWrappedAsset::decode(&mut expected.encode().as_slice()).unwrap();
+            assert_eq!(expected, actual);
+        }
+
+        fn attested_mint(
+            world_state_view: &mut WorldStateView,
+            lock: &LockAsset,
+            wrapped_asset_id: Id,
+            account_id: Id,
+        ) -> MintWrappedAsset {
+            let guardian_key = vec![7];
+            world_state_view
+                .guardian_sets
+                .push(GuardianSet::new(0, vec![guardian_key.clone()], 0));
+            let payload = lock.transfer_payload();
+            let signature = crate::crypto::sign(&guardian_key, &crate::crypto::hash(&payload));
+            let mut attestation = Attestation::new(payload, 0);
+            attestation.signatures.push((0, signature));
+            MintWrappedAsset {
+                wrapped_asset_id,
+                account_id,
+                attestation,
+            }
+        }
+
+        #[test]
+        fn mint_wrapped_asset_rejects_a_replayed_attestation() {
+            let mut world_state_view = WorldStateView::new();
+            let account_id = Id::new("account", "other_domain");
+            world_state_view
+                .world
+                .accounts
+                .insert(account_id.clone(), Account::new(account_id.clone()));
+            world_state_view
+                .bridge_contracts
+                .insert("other_domain".to_string(), vec!["bridge_address".to_string()]);
+            let lock = LockAsset {
+                asset_id: Id::new("xor", "domain"),
+                account_id: Id::new("account", "domain"),
+                amount: 2002,
+                target_domain: "other_domain".to_string(),
+                origin_bridge_address: "bridge_address".to_string(),
+            };
+            let mint = attested_mint(
+                &mut world_state_view,
+                &lock,
+                Id::new("xor.e", "other_domain"),
+                account_id,
+            );
+            assert!(mint.execute(&mut world_state_view).is_ok());
+            assert!(mint.execute(&mut world_state_view).is_err());
+        }
+
+        #[test]
+        fn mint_wrapped_asset_rejects_an_untrusted_bridge_address() {
+            let mut world_state_view = WorldStateView::new();
+            let account_id = Id::new("account", "other_domain");
+            world_state_view
+                .world
+                .accounts
+                .insert(account_id.clone(), Account::new(account_id.clone()));
+            let lock = LockAsset {
+                asset_id: Id::new("xor", "domain"),
+                account_id: Id::new("account", "domain"),
+                amount: 2002,
+                target_domain: "other_domain".to_string(),
+                origin_bridge_address: "bridge_address".to_string(),
+            };
+            let mint = attested_mint(
+                &mut world_state_view,
+                &lock,
+                Id::new("xor.e", "other_domain"),
+                account_id,
+            );
+            assert!(mint.execute(&mut world_state_view).is_err());
+        }
+
+        #[test]
+        fn mint_wrapped_asset_can_be_retried_after_a_rejected_attempt() {
+            let mut world_state_view = WorldStateView::new();
+            let account_id = Id::new("account", "other_domain");
+            world_state_view
+                .world
+                .accounts
+                .insert(account_id.clone(), Account::new(account_id.clone()));
+            let lock = LockAsset {
+                asset_id: Id::new("xor", "domain"),
+                account_id: Id::new("account", "domain"),
+                amount: 2002,
+                target_domain: "other_domain".to_string(),
+                origin_bridge_address: "bridge_address".to_string(),
+            };
+            let mint = attested_mint(
+                &mut world_state_view,
+                &lock,
+                Id::new("xor.e", "other_domain"),
+                account_id.clone(),
+            );
+            // No bridge_contracts entry yet: the first attempt is rejected as untrusted, and
+            // must NOT have burned the attestation's sequence number.
+            assert!(mint.execute(&mut world_state_view).is_err());
+            assert!(!world_state_view
+                .processed_attestations
+                .contains(&mint.attestation.sequence));
+            world_state_view
+                .bridge_contracts
+                .insert("other_domain".to_string(), vec!["bridge_address".to_string()]);
+            // The same attestation, retried now that the bridge is trusted, must succeed and
+            // actually credit the wrapped asset.
+            assert!(mint.execute(&mut world_state_view).is_ok());
+            let credited = world_state_view
+                .world
+                .account(&account_id)
+                .unwrap()
+                .assets
+                .get(&Id::new("xor.e", "other_domain"))
+                .unwrap()
+                .quantity;
+            assert_eq!(credited, lock.amount);
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use sha2::{Digest, Sha256};
+
+/// Hashes `payload` with SHA-256. Used wherever the rest of the crate needs a fixed-size digest
+/// of arbitrary bytes: transaction hashes, Merkle tree nodes, and the message an `Attestation`'s
+/// signatures are checked against.
+pub fn hash(payload: &[u8]) -> Vec<u8> {
+    Sha256::digest(payload).to_vec()
+}
+
+/// Signs `hash` with `key`.
+///
+/// This is a placeholder symmetric scheme (`sign(key, hash) = hash(key || hash)`), standing in
+/// for real asymmetric signatures until guardians and account signatories carry actual key pairs.
+/// It satisfies `verify(key, hash, sign(key, hash))` but, unlike a real signature, anyone who
+/// knows `key` can both sign and verify with it.
+pub fn sign(key: &[u8], message_hash: &[u8]) -> Vec<u8> {
+    hash(&[key, message_hash].concat())
+}
+
+/// Checks a signature produced by `sign`.
+pub fn verify(key: &[u8], message_hash: &[u8], signature: &[u8]) -> bool {
+    sign(key, message_hash) == signature
+}
@@ -0,0 +1,43 @@
+use crate::prelude::*;
+
+/// A domain: a namespace grouping related accounts.
+#[derive(Clone, Debug, Default)]
+pub struct Domain {
+    pub id: String,
+}
+
+impl Domain {
+    pub fn new(id: String) -> Self {
+        Domain { id }
+    }
+}
+
+pub mod isi {
+    use super::*;
+    use crate::isi::Contract;
+    use parity_scale_codec::{Decode, Encode};
+    use serde::{Deserialize, Serialize};
+
+    /// Creates a new, empty domain.
+    #[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
+    pub struct CreateDomain {
+        pub domain_name: String,
+    }
+
+    impl Instruction for CreateDomain {
+        fn execute(&self, world_state_view: &mut WorldStateView) -> Result<(), String> {
+            world_state_view
+                .world
+                .domains
+                .entry(self.domain_name.clone())
+                .or_insert_with(|| Domain::new(self.domain_name.clone()));
+            Ok(())
+        }
+    }
+
+    impl std::convert::From<CreateDomain> for Contract {
+        fn from(command_payload: CreateDomain) -> Self {
+            Contract::CreateDomain(command_payload)
+        }
+    }
+}
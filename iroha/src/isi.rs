@@ -1,5 +1,6 @@
-use crate::{account, asset, domain, peer, wsv::WorldStateView};
+use crate::{account, asset, bridge, domain, peer, wsv::WorldStateView};
 use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
 
 /// Identification of an Iroha's entites. Consists of Entity's name and Domain's name.
 ///
@@ -10,7 +11,7 @@ use parity_scale_codec::{Decode, Encode};
 ///
 /// let id = Id::new("gold", "mine");
 /// ```
-#[derive(Clone, Debug, PartialEq, Eq, std::hash::Hash, Encode, Decode)]
+#[derive(Clone, Debug, PartialEq, Eq, std::hash::Hash, Encode, Decode, Serialize, Deserialize)]
 pub struct Id(pub String, pub String);
 
 impl Id {
@@ -30,8 +31,9 @@ pub trait Instruction {
     fn execute(&self, world_state_view: &mut WorldStateView) -> Result<(), String>;
 }
 
-///
-#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+/// One of the ledger's hardcoded smart contracts, or a `Custom` payload dispatched to a
+/// runtime-registered handler.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
 pub enum Contract {
     AddSignatory(account::isi::AddSignatory),
     AppendRole(account::isi::AppendRole),
@@ -42,6 +44,15 @@ pub enum Contract {
     CreateAsset(asset::isi::CreateAsset),
     CreateDomain(domain::isi::CreateDomain),
     AddPeer(peer::isi::AddPeer),
+    LockAsset(bridge::isi::LockAsset),
+    MintWrappedAsset(bridge::isi::MintWrappedAsset),
+    RegisterBridgeContract(bridge::isi::RegisterBridgeContract),
+    /// An instruction conforming to a handler registered at runtime via
+    /// `WorldStateView::register_instruction`, rather than a hardcoded variant of this enum.
+    Custom {
+        interface_id: String,
+        payload: Vec<u8>,
+    },
 }
 
 impl Contract {
@@ -52,9 +63,38 @@ impl Contract {
             CreateAccount(instruction) => instruction.execute(world_state_view),
             CreateDomain(instruction) => instruction.execute(world_state_view),
             TransferAsset(instruction) => instruction.execute(world_state_view),
+            LockAsset(instruction) => instruction.execute(world_state_view),
+            MintWrappedAsset(instruction) => instruction.execute(world_state_view),
+            RegisterBridgeContract(instruction) => instruction.execute(world_state_view),
+            Custom {
+                interface_id,
+                payload,
+            } => {
+                let handler = world_state_view
+                    .custom_instructions
+                    .get(interface_id)
+                    .cloned()
+                    .ok_or_else(|| {
+                        format!("No instruction registered for interface id: {}", interface_id)
+                    })?;
+                handler.execute(payload, world_state_view)
+            }
             _ => Err("Instruction is not supported yet.".to_string()),
         }
     }
+
+    /// Renders this instruction as a human-readable JSON document, e.g. for wallets, block
+    /// explorers, and test harnesses. Since JSON and SCALE derive from the same struct field
+    /// order, a `Contract` built from this string encodes to exactly the same SCALE bytes as
+    /// one built programmatically.
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string(self).map_err(|error| error.to_string())
+    }
+
+    /// Parses a `Contract` out of the JSON document produced by `to_json`.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|error| error.to_string())
+    }
 }
 
 impl std::convert::From<&Contract> for Vec<u8> {
@@ -84,7 +124,6 @@ pub trait Property {
 }
 
 impl Property for Contract {
-    //TODO: implement
     fn relations(&self) -> Vec<Relation> {
         use Relation::*;
         match self {
@@ -95,6 +134,18 @@ impl Property for Contract {
                     OwnedBy(instruction.source_account_id),
                 ]
             }
+            Contract::AddAssetQuantity(instruction) => {
+                vec![OwnedBy(instruction.account_id.clone())]
+            }
+            Contract::LockAsset(instruction) => {
+                vec![
+                    OwnedBy(instruction.account_id.clone()),
+                    OwnedBy(instruction.escrow_account_id()),
+                ]
+            }
+            Contract::MintWrappedAsset(instruction) => {
+                vec![OwnedBy(instruction.account_id.clone())]
+            }
             _ => Vec::new(),
         }
     }
@@ -105,14 +156,39 @@ pub trait Assetibility {
 }
 
 impl Assetibility for Contract {
-    //TODO: implement
     fn assets(&self) -> Vec<Id> {
         match self {
             Contract::TransferAsset(instruction) => {
                 let instruction = instruction.clone();
                 vec![instruction.asset_id]
             }
+            Contract::AddAssetQuantity(instruction) => vec![instruction.asset_id.clone()],
+            Contract::LockAsset(instruction) => vec![instruction.asset_id.clone()],
+            Contract::MintWrappedAsset(instruction) => vec![instruction.wrapped_asset_id.clone()],
             _ => Vec::new(),
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asset::isi::TransferAsset;
+
+    #[test]
+    fn contract_json_round_trips_to_the_same_scale_bytes() {
+        let expected = Contract::TransferAsset(TransferAsset {
+            source_account_id: Id::new("source", "domain"),
+            destination_account_id: Id::new("destination", "domain"),
+            asset_id: Id::new("xor", "domain"),
+            description: "description".to_string(),
+            amount: 2002,
+        });
+        let json = expected.to_json().unwrap();
+        let actual = Contract::from_json(&json).unwrap();
+        assert_eq!(expected, actual);
+        let expected_bytes: Vec<u8> = (&expected).into();
+        let actual_bytes: Vec<u8> = (&actual).into();
+        assert_eq!(expected_bytes, actual_bytes);
+    }
+}
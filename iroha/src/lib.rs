@@ -0,0 +1,17 @@
+// parity-scale-codec's derive macros predate rustc's unexpected-cfg lint; every `#[derive(Encode,
+// Decode)]` in this crate trips it regardless of what the annotated type looks like.
+#![allow(unexpected_cfgs)]
+
+pub mod account;
+pub mod asset;
+pub mod attestation;
+pub mod barrier;
+pub mod bridge;
+pub mod crypto;
+pub mod domain;
+pub mod isi;
+pub mod peer;
+pub mod prelude;
+pub mod registry;
+pub mod transaction;
+pub mod wsv;
@@ -0,0 +1,22 @@
+use crate::prelude::*;
+
+pub mod isi {
+    use super::*;
+    use crate::isi::Contract;
+    use parity_scale_codec::{Decode, Encode};
+    use serde::{Deserialize, Serialize};
+
+    /// Registers a new peer so it can participate in consensus. `Contract::invoke` does not
+    /// execute this instruction: it returns an error and registers no peer.
+    #[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
+    pub struct AddPeer {
+        pub address: String,
+        pub public_key: PublicKey,
+    }
+
+    impl std::convert::From<AddPeer> for Contract {
+        fn from(command_payload: AddPeer) -> Self {
+            Contract::AddPeer(command_payload)
+        }
+    }
+}
@@ -0,0 +1,11 @@
+//! Re-exports the types most commonly needed together: an instruction's `execute` almost always
+//! needs `Id`, `Instruction`, `Asset` and `WorldStateView` in scope at once.
+pub use crate::account::Account;
+pub use crate::asset::Asset;
+pub use crate::attestation::{active_guardian_set, Attestation, GuardianSet, PublicKey};
+pub use crate::barrier::{AllowPaidExecutionFrom, AllowUnpaidExecutionFrom, Barrier, Fee, Weight, WeightBounds};
+pub use crate::domain::Domain;
+pub use crate::isi::{Assetibility, Contract, Id, Instruction, Property, Relation};
+pub use crate::registry::{CustomInstruction, InstructionRegistry};
+pub use crate::wsv::{World, WorldStateView};
+pub use crate::{account, asset, bridge, domain, peer};
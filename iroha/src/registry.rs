@@ -0,0 +1,73 @@
+use crate::prelude::*;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Implemented by handlers for instructions that don't ship as a hardcoded `Contract` variant.
+/// A handler receives the raw `payload` bytes carried by `Contract::Custom`, in whatever
+/// encoding the handler defines for its interface id, and is
+/// responsible for decoding it itself before acting on `world_state_view` — the same way a
+/// program can support any implementation conforming to a shared instruction interface, without
+/// the core enum having to know about it up front.
+pub trait CustomInstruction {
+    fn execute(&self, payload: &[u8], world_state_view: &mut WorldStateView) -> Result<(), String>;
+}
+
+/// Maps an interface id to the handler registered for it. Kept on `WorldStateView` so
+/// downstream crates can ship new special instructions at runtime, via
+/// `WorldStateView::register_instruction`, without forking `Contract`.
+pub type InstructionRegistry = HashMap<String, Arc<dyn CustomInstruction>>;
+
+impl WorldStateView {
+    /// Registers `handler` to be invoked for every `Contract::Custom` instruction carrying
+    /// `interface_id`. Re-registering an id replaces the handler previously registered for it.
+    pub fn register_instruction(
+        &mut self,
+        interface_id: String,
+        handler: Arc<dyn CustomInstruction>,
+    ) {
+        self.custom_instructions.insert(interface_id, handler);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::isi::Contract;
+
+    struct Greet;
+
+    impl CustomInstruction for Greet {
+        fn execute(&self, payload: &[u8], world_state_view: &mut WorldStateView) -> Result<(), String> {
+            let domain_name = String::from_utf8(payload.to_vec())
+                .map_err(|_| "Payload is not valid UTF-8.".to_string())?;
+            world_state_view
+                .world
+                .domains
+                .entry(domain_name.clone())
+                .or_insert_with(|| crate::domain::Domain::new(domain_name));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn contract_custom_dispatches_to_the_registered_handler() {
+        let mut world_state_view = WorldStateView::new();
+        world_state_view.register_instruction("greet".to_string(), Arc::new(Greet));
+        let instruction = Contract::Custom {
+            interface_id: "greet".to_string(),
+            payload: b"hello".to_vec(),
+        };
+        assert!(instruction.invoke(&mut world_state_view).is_ok());
+        assert!(world_state_view.world.domains.contains_key("hello"));
+    }
+
+    #[test]
+    fn contract_custom_rejects_an_unregistered_interface_id() {
+        let mut world_state_view = WorldStateView::new();
+        let instruction = Contract::Custom {
+            interface_id: "unknown".to_string(),
+            payload: Vec::new(),
+        };
+        assert!(instruction.invoke(&mut world_state_view).is_err());
+    }
+}
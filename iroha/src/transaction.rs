@@ -0,0 +1,319 @@
+use crate::prelude::*;
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+/// An ordered batch of `Contract`s submitted together by `creator` and executed atomically:
+/// every instruction must apply against a scratch copy of `WorldStateView` for any of them to
+/// be committed. Turns `Contract::invoke`'s fire-and-forget dispatch into an auditable,
+/// provable unit of execution.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct Transaction {
+    pub instructions: Vec<Contract>,
+    pub creator: Id,
+    pub nonce: u64,
+    pub signatures: Vec<Vec<u8>>,
+}
+
+impl Transaction {
+    pub fn new(instructions: Vec<Contract>, creator: Id, nonce: u64) -> Self {
+        Transaction {
+            instructions,
+            creator,
+            nonce,
+            signatures: Vec::new(),
+        }
+    }
+
+    /// Hash identifying this transaction, used both as the leaf appended to a block's
+    /// `MerkleAccumulator` and as the message `verify_signed_transaction` checks signatures
+    /// over.
+    pub fn hash(&self) -> Vec<u8> {
+        crate::crypto::hash(&self.signing_payload())
+    }
+
+    /// The canonical bytes a creator signs over: the instructions, creator and nonce, but not
+    /// `signatures` itself.
+    fn signing_payload(&self) -> Vec<u8> {
+        (self.instructions.clone(), self.creator.clone(), self.nonce).encode()
+    }
+
+    /// Applies every instruction against a scratch copy of `world_state_view`, committing the
+    /// resulting state only if all of them succeed, and returns the `WriteSet` describing what
+    /// changed. `world_state_view` is left untouched if any instruction returns `Err`.
+    pub fn execute(&self, world_state_view: &mut WorldStateView) -> Result<WriteSet, String> {
+        let mut scratch = world_state_view.clone();
+        for instruction in &self.instructions {
+            instruction.invoke(&mut scratch)?;
+        }
+        let write_set = self.collect_write_set(&mut scratch);
+        *world_state_view = scratch;
+        Ok(write_set)
+    }
+
+    /// Builds the `WriteSet` by re-reading, from `scratch` after every instruction has applied, every
+    /// account/asset key the batch's instructions touch according to `Property::relations` and
+    /// `Assetibility::assets`.
+    fn collect_write_set(&self, scratch: &mut WorldStateView) -> WriteSet {
+        let mut write_set = Vec::new();
+        for instruction in &self.instructions {
+            for relation in instruction.relations() {
+                let account_id = match relation {
+                    Relation::OwnedBy(id) | Relation::GoingTo(id) => id,
+                };
+                for asset_id in instruction.assets() {
+                    if let Some(account) = scratch.world.account(&account_id) {
+                        if let Some(asset) = account.assets.get(&asset_id) {
+                            write_set.push(WriteSetEntry {
+                                account_id: account_id.clone(),
+                                asset_id,
+                                quantity: asset.quantity,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        write_set.dedup();
+        write_set
+    }
+}
+
+impl std::convert::From<&Transaction> for Vec<u8> {
+    fn from(transaction: &Transaction) -> Self {
+        transaction.encode()
+    }
+}
+
+impl std::convert::From<Vec<u8>> for Transaction {
+    fn from(bytes: Vec<u8>) -> Self {
+        Transaction::decode(&mut bytes.as_slice()).expect("Failed to deserialize payload.")
+    }
+}
+
+/// Verifies that `transaction.signatures` contains at least one valid signature, over the
+/// transaction's `signing_payload`, from one of the creator account's registered signatories.
+/// Should be checked before `Transaction::execute` is ever called.
+pub fn verify_signed_transaction(
+    transaction: &Transaction,
+    world_state_view: &mut WorldStateView,
+) -> Result<(), String> {
+    if transaction.signatures.is_empty() {
+        return Err("Transaction has no creator signatures.".to_string());
+    }
+    let hash = crate::crypto::hash(&transaction.signing_payload());
+    let account = world_state_view
+        .world
+        .account(&transaction.creator)
+        .ok_or_else(|| format!("Account not found: {:?}", transaction.creator))?;
+    let valid = transaction.signatures.iter().any(|signature| {
+        account
+            .signatories
+            .iter()
+            .any(|public_key| crate::crypto::verify(public_key, &hash, signature))
+    });
+    if !valid {
+        return Err(
+            "Transaction signature does not match any of the creator's signatories.".to_string(),
+        );
+    }
+    Ok(())
+}
+
+/// A single account/asset balance changed by a committed `Transaction`, recorded so a light
+/// client can audit exactly what happened without replaying every instruction.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct WriteSetEntry {
+    pub account_id: Id,
+    pub asset_id: Id,
+    pub quantity: u128,
+}
+
+pub type WriteSet = Vec<WriteSetEntry>;
+
+/// A single sibling hash on the path from a leaf to the root of a `MerkleAccumulator`, together
+/// with which side of the pairing it sits on (`true` means the sibling is to the right of the
+/// node being proved, so the pair hashes as `hash(node, sibling)`).
+pub type AccumulatorSibling = (Vec<u8>, bool);
+
+/// An inclusion proof for one leaf of a `MerkleAccumulator`: the sibling hashes along the path
+/// from that leaf up to the root, bottom-up.
+#[derive(Clone, Debug, PartialEq, Encode, Decode, Serialize, Deserialize)]
+pub struct AccumulatorProof {
+    pub siblings: Vec<AccumulatorSibling>,
+}
+
+/// A Merkle accumulator of committed transaction hashes, appended to one leaf per block so
+/// light clients can later prove a transaction was included without downloading the block.
+#[derive(Clone, Debug, Default)]
+pub struct MerkleAccumulator {
+    leaves: Vec<Vec<u8>>,
+}
+
+impl MerkleAccumulator {
+    pub fn new() -> Self {
+        MerkleAccumulator { leaves: Vec::new() }
+    }
+
+    pub fn append(&mut self, transaction_hash: Vec<u8>) {
+        self.leaves.push(transaction_hash);
+    }
+
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// All levels of the tree, from the leaves (index 0) up to the single-node root. A level
+    /// with an odd number of nodes duplicates its last node so every pair has a partner.
+    fn levels(&self) -> Vec<Vec<Vec<u8>>> {
+        let mut levels = vec![self.leaves.clone()];
+        while levels.last().expect("always has at least the leaf level").len() > 1 {
+            let current = levels.last().expect("checked above");
+            let next = current
+                .chunks(2)
+                .map(|pair| {
+                    let right = pair.get(1).unwrap_or(&pair[0]);
+                    crate::crypto::hash(&[pair[0].clone(), right.clone()].concat())
+                })
+                .collect();
+            levels.push(next);
+        }
+        levels
+    }
+
+    pub fn root(&self) -> Option<Vec<u8>> {
+        self.levels().last().and_then(|level| level.first().cloned())
+    }
+
+    pub fn prove(&self, mut index: usize) -> Option<AccumulatorProof> {
+        if index >= self.leaves.len() {
+            return None;
+        }
+        let levels = self.levels();
+        let mut siblings = Vec::new();
+        for level in &levels[..levels.len() - 1] {
+            let is_left = index.is_multiple_of(2);
+            let sibling_index = if is_left { index + 1 } else { index - 1 };
+            let sibling = level.get(sibling_index).unwrap_or(&level[index]).clone();
+            siblings.push((sibling, is_left));
+            index /= 2;
+        }
+        Some(AccumulatorProof { siblings })
+    }
+}
+
+/// Recomputes the Merkle root by folding `proof`'s siblings into `transaction_hash` bottom-up,
+/// and checks the result matches `root`.
+pub fn verify(root: &[u8], transaction_hash: &[u8], proof: &AccumulatorProof) -> bool {
+    let mut node = transaction_hash.to_vec();
+    for (sibling, node_is_left) in &proof.siblings {
+        node = if *node_is_left {
+            crate::crypto::hash(&[node, sibling.clone()].concat())
+        } else {
+            crate::crypto::hash(&[sibling.clone(), node].concat())
+        };
+    }
+    node == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(byte: u8) -> Vec<u8> {
+        crate::crypto::hash(&[byte])
+    }
+
+    #[test]
+    fn proof_verifies_every_leaf_of_an_odd_sized_accumulator() {
+        let mut accumulator = MerkleAccumulator::new();
+        for byte in 0..5u8 {
+            accumulator.append(leaf(byte));
+        }
+        let root = accumulator.root().unwrap();
+        for index in 0..5 {
+            let proof = accumulator.prove(index).unwrap();
+            assert!(verify(&root, &leaf(index as u8), &proof));
+        }
+    }
+
+    #[test]
+    fn proof_is_rejected_for_the_wrong_transaction_hash() {
+        let mut accumulator = MerkleAccumulator::new();
+        accumulator.append(leaf(0));
+        accumulator.append(leaf(1));
+        let root = accumulator.root().unwrap();
+        let proof = accumulator.prove(0).unwrap();
+        assert!(!verify(&root, &leaf(2), &proof));
+    }
+
+    #[test]
+    fn prove_returns_none_for_an_out_of_range_index() {
+        let mut accumulator = MerkleAccumulator::new();
+        accumulator.append(leaf(0));
+        assert!(accumulator.prove(1).is_none());
+    }
+
+    fn signed_transaction(key: &[u8], tamper: bool) -> (Transaction, WorldStateView) {
+        let creator = Id::new("account", "domain");
+        let mut world_state_view = WorldStateView::new();
+        let mut account = Account::new(creator.clone());
+        account.signatories.push(key.to_vec());
+        world_state_view.world.accounts.insert(creator.clone(), account);
+        let mut transaction = Transaction::new(Vec::new(), creator, 0);
+        let hash = crate::crypto::hash(&transaction.signing_payload());
+        let mut signature = crate::crypto::sign(key, &hash);
+        if tamper {
+            signature[0] ^= 0xFF;
+        }
+        transaction.signatures.push(signature);
+        (transaction, world_state_view)
+    }
+
+    #[test]
+    fn verify_signed_transaction_accepts_a_valid_signatory_signature() {
+        let key = vec![4, 5, 6];
+        let (transaction, mut world_state_view) = signed_transaction(&key, false);
+        assert!(verify_signed_transaction(&transaction, &mut world_state_view).is_ok());
+    }
+
+    #[test]
+    fn verify_signed_transaction_rejects_a_signature_from_no_registered_signatory() {
+        let key = vec![4, 5, 6];
+        let (transaction, mut world_state_view) = signed_transaction(&key, true);
+        assert!(verify_signed_transaction(&transaction, &mut world_state_view).is_err());
+    }
+
+    #[test]
+    fn write_set_covers_non_transfer_instructions() {
+        let creator = Id::new("account", "domain");
+        let mut world_state_view = WorldStateView::new();
+        world_state_view
+            .world
+            .accounts
+            .insert(creator.clone(), Account::new(creator.clone()));
+        let asset_id = Id::new("xor", "domain");
+        let transaction = Transaction::new(
+            vec![asset::isi::AddAssetQuantity {
+                asset_id: asset_id.clone(),
+                account_id: creator.clone(),
+                amount: 42,
+            }
+            .into()],
+            creator.clone(),
+            0,
+        );
+        let write_set = transaction.execute(&mut world_state_view).unwrap();
+        assert_eq!(
+            write_set,
+            vec![WriteSetEntry {
+                account_id: creator,
+                asset_id,
+                quantity: 42,
+            }]
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use crate::account::Account;
+use crate::attestation::GuardianSet;
+use crate::barrier::{Barrier, Weight, WeightBounds};
+use crate::bridge::{BridgeContracts, WrappedAssetRegistry};
+use crate::domain::Domain;
+use crate::isi::Id;
+use crate::registry::InstructionRegistry;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Everything in the ledger that isn't metadata about how instructions get executed: the
+/// accounts and domains an instruction actually reads and mutates.
+#[derive(Clone, Debug, Default)]
+pub struct World {
+    pub accounts: HashMap<Id, Account>,
+    pub domains: HashMap<String, Domain>,
+}
+
+impl World {
+    /// Looks up the account with `id`, if one has been created.
+    pub fn account(&mut self, id: &Id) -> Option<&mut Account> {
+        self.accounts.get_mut(id)
+    }
+
+    /// Looks up the domain named `id`, if one has been created.
+    pub fn domain(&mut self, id: &str) -> Option<&mut Domain> {
+        self.domains.get_mut(id)
+    }
+}
+
+/// The full state an instruction executes against: the `World` of accounts and domains, plus
+/// every piece of cross-cutting ledger state a `Contract` variant needs to consult or update —
+/// the bridge's wrapped-asset and trusted-contract registries, replayed attestation sequences,
+/// the guardian sets attestations verify against, and the barrier/weight/custom-instruction
+/// machinery instructions are dispatched through.
+///
+/// `Clone` is relied on by both `Transaction::execute` and `invoke_with_barriers`, which apply a
+/// batch against a scratch copy and only commit it back on full success.
+#[derive(Clone)]
+pub struct WorldStateView {
+    pub world: World,
+    pub wrapped_assets: WrappedAssetRegistry,
+    pub bridge_contracts: BridgeContracts,
+    /// Attestation sequence numbers already minted against, so a captured attestation can never
+    /// be replayed to mint the same locked transfer twice.
+    pub processed_attestations: HashSet<u64>,
+    pub guardian_sets: Vec<GuardianSet>,
+    pub barriers: Vec<Arc<dyn Barrier>>,
+    pub weight_bounds: WeightBounds,
+    pub custom_instructions: InstructionRegistry,
+}
+
+impl Default for WorldStateView {
+    fn default() -> Self {
+        WorldStateView {
+            world: World::default(),
+            wrapped_assets: WrappedAssetRegistry::new(),
+            bridge_contracts: BridgeContracts::new(),
+            processed_attestations: HashSet::new(),
+            guardian_sets: Vec::new(),
+            barriers: Vec::new(),
+            weight_bounds: WeightBounds {
+                max_weight_per_block: Weight::MAX,
+            },
+            custom_instructions: InstructionRegistry::new(),
+        }
+    }
+}
+
+impl WorldStateView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}